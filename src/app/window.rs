@@ -3,14 +3,317 @@ use tracing::{debug, warn};
 use crate::AppWindow;
 
 #[cfg(target_os = "windows")]
-use windows::Win32::Foundation::{BOOL, HWND, LPARAM, RECT};
+use windows::Win32::Foundation::{BOOL, ERROR_ALREADY_EXISTS, GetLastError, HWND, LPARAM, LRESULT, POINT, RECT, WPARAM};
 #[cfg(target_os = "windows")]
 use windows::Win32::UI::WindowsAndMessaging::{
-    EnumWindows, GetWindowRect, GetWindowThreadProcessId, IsWindowVisible, 
-    SetWindowPos, GetWindow, GW_OWNER, SWP_NOSIZE, SWP_NOZORDER, HWND_TOP
+    EnumWindows, GetWindowRect, GetWindowThreadProcessId, IsWindowVisible,
+    SetWindowPos, GetWindow, GW_OWNER, SWP_NOACTIVATE, SWP_NOSIZE, SWP_NOZORDER, HWND_TOP
 };
 #[cfg(target_os = "windows")]
-use windows::Win32::Graphics::Gdi::{MonitorFromWindow, GetMonitorInfoW, MONITORINFO, MONITOR_DEFAULTTOPRIMARY};
+use windows::Win32::UI::WindowsAndMessaging::GetCursorPos;
+#[cfg(target_os = "windows")]
+use windows::Win32::Graphics::Gdi::{
+    EnumDisplayMonitors, GetMonitorInfoW, HDC, HMONITOR, MONITORINFO, MONITORINFOEXW,
+    MONITORINFOF_PRIMARY, MonitorFromPoint, MonitorFromRect, MonitorFromWindow,
+    MONITOR_DEFAULTTONEAREST, MONITOR_DEFAULTTOPRIMARY,
+};
+#[cfg(target_os = "windows")]
+use windows::Win32::UI::HiDpi::{
+    GetDpiForMonitor, GetDpiForWindow, MDT_EFFECTIVE_DPI, SetProcessDpiAwarenessContext,
+    DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2,
+};
+#[cfg(target_os = "windows")]
+use windows::Win32::UI::WindowsAndMessaging::{
+    CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW, PostMessageW,
+    RegisterClassW, RegisterWindowMessageW, SetForegroundWindow, ShowWindow, TranslateMessage,
+    HWND_BROADCAST, MSG, SW_RESTORE, WNDCLASSW, WINDOW_EX_STYLE, WINDOW_STYLE,
+    WM_CLOSE, WM_DESTROY, WM_EXITSIZEMOVE, WM_MOVE,
+};
+#[cfg(target_os = "windows")]
+use windows::Win32::UI::Shell::{DefSubclassProc, SetWindowSubclass};
+#[cfg(target_os = "windows")]
+use windows::Win32::System::Threading::CreateMutexW;
+#[cfg(target_os = "windows")]
+use windows::core::w;
+#[cfg(target_os = "windows")]
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+// A single display, as reported by the OS. Populated by `monitors()`.
+#[cfg(target_os = "windows")]
+#[derive(Debug, Clone)]
+pub struct MonitorInfo {
+    /// Full monitor bounds, in virtual-screen coordinates.
+    pub rc_monitor: RECT,
+    /// Work area (excludes the taskbar and other docked windows).
+    pub rc_work: RECT,
+    /// e.g. "\\\\.\\DISPLAY1"
+    pub device_name: String,
+    pub is_primary: bool,
+}
+
+// Where `show_and_center` should place the dashboard.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum MonitorTarget {
+    /// The OS-designated primary monitor.
+    #[default]
+    Primary,
+    /// Whichever monitor the mouse cursor is currently over.
+    Cursor,
+    /// An explicit index into the `monitors()` list.
+    Index(usize),
+}
+
+#[cfg(target_os = "windows")]
+struct EnumMonitorData {
+    monitors: Vec<MonitorInfo>,
+}
+
+#[cfg(target_os = "windows")]
+unsafe extern "system" fn enum_monitor_proc(
+    hmonitor: HMONITOR,
+    _hdc: HDC,
+    _rect: *mut RECT,
+    lparam: LPARAM,
+) -> BOOL {
+    // Safety requirement: FFI callback, must only touch the pointer we handed in.
+    unsafe {
+        let data = &mut *(lparam.0 as *mut EnumMonitorData);
+
+        let mut info = MONITORINFOEXW {
+            monitorInfo: MONITORINFO {
+                cbSize: std::mem::size_of::<MONITORINFOEXW>() as u32,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        if GetMonitorInfoW(hmonitor, &mut info as *mut MONITORINFOEXW as *mut MONITORINFO).as_bool() {
+            let device_name = String::from_utf16_lossy(
+                &info.szDevice[..info.szDevice.iter().position(|&c| c == 0).unwrap_or(info.szDevice.len())],
+            );
+
+            data.monitors.push(MonitorInfo {
+                rc_monitor: info.monitorInfo.rcMonitor,
+                rc_work: info.monitorInfo.rcWork,
+                device_name,
+                is_primary: (info.monitorInfo.dwFlags & MONITORINFOF_PRIMARY) != 0,
+            });
+        }
+
+        BOOL(1) // Continue enumeration
+    }
+}
+
+/// Enumerates every display attached to the system.
+#[cfg(target_os = "windows")]
+pub fn monitors() -> Vec<MonitorInfo> {
+    let mut data = EnumMonitorData { monitors: Vec::new() };
+
+    unsafe {
+        let _ = EnumDisplayMonitors(
+            None,
+            None,
+            Some(enum_monitor_proc),
+            LPARAM(&mut data as *mut _ as _),
+        );
+    }
+
+    data.monitors
+}
+
+// Resolves the work-area rect (`rcWork`) of an `HMONITOR`.
+#[cfg(target_os = "windows")]
+fn work_area_of(hmonitor: HMONITOR) -> Option<RECT> {
+    let mut monitor_info = MONITORINFO {
+        cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+        ..Default::default()
+    };
+
+    if unsafe { GetMonitorInfoW(hmonitor, &mut monitor_info) }.as_bool() {
+        Some(monitor_info.rcWork)
+    } else {
+        None
+    }
+}
+
+// Resolves the HMONITOR the window should be placed on for a given target.
+#[cfg(target_os = "windows")]
+fn resolve_target_monitor(hwnd: HWND, target: MonitorTarget) -> Option<HMONITOR> {
+    match target {
+        MonitorTarget::Primary => {
+            if let Some(m) = monitors().into_iter().find(|m| m.is_primary) {
+                // Re-resolve via a point inside its bounds; monitors() doesn't hand out HMONITORs.
+                return Some(unsafe { MonitorFromPoint(monitor_center(&m.rc_monitor), MONITOR_DEFAULTTOPRIMARY) });
+            }
+            // Fall back to whatever Windows considers primary for this window.
+            Some(unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTOPRIMARY) })
+        }
+        MonitorTarget::Cursor => {
+            let mut pt = POINT::default();
+            unsafe {
+                let _ = GetCursorPos(&mut pt);
+            }
+            Some(unsafe { MonitorFromPoint(pt, MONITOR_DEFAULTTOPRIMARY) })
+        }
+        MonitorTarget::Index(index) => {
+            let m = monitors().into_iter().nth(index)?;
+            Some(unsafe { MonitorFromPoint(monitor_center(&m.rc_monitor), MONITOR_DEFAULTTOPRIMARY) })
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn monitor_center(rect: &RECT) -> POINT {
+    POINT {
+        x: (rect.left + rect.right) / 2,
+        y: (rect.top + rect.bottom) / 2,
+    }
+}
+
+// Effective DPI of a monitor, e.g. 96 at 100% scaling, 144 at 150%.
+#[cfg(target_os = "windows")]
+fn effective_dpi(hmonitor: HMONITOR) -> u32 {
+    let mut dpi_x: u32 = 96;
+    let mut dpi_y: u32 = 96;
+    if unsafe { GetDpiForMonitor(hmonitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) }.is_err() {
+        warn!("GetDpiForMonitor failed; assuming 96 DPI");
+    }
+    dpi_x
+}
+
+// Marks the process per-monitor-DPI-aware so GetWindowRect/SetWindowPos deal in physical
+// pixels consistent with rcWork on every monitor, even across mixed-DPI setups.
+//
+// Must be called at process entry, before the Slint backend / `AppWindow` is created:
+// `SetProcessDpiAwarenessContext` has to run before the window-system backend initializes,
+// and Slint's Windows backend sets its own DPI awareness as part of that init. Calling this
+// any later (e.g. from `show_and_center_on`) is a no-op that fails with ERROR_ACCESS_DENIED.
+#[cfg(target_os = "windows")]
+pub fn ensure_process_dpi_aware() {
+    static ONCE: std::sync::Once = std::sync::Once::new();
+    ONCE.call_once(|| unsafe {
+        if SetProcessDpiAwarenessContext(DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2).is_err() {
+            warn!("Failed to opt into per-monitor DPI awareness; window placement may be off on mixed-DPI setups");
+        }
+    });
+}
+
+// Pure clamp/shrink/slide math, kept separate from the Win32 calls in `adjust_to_fit` so it's
+// unit-testable without a real HWND or monitor: if `desired` is wider or taller than `work`,
+// shrink that dimension to `work`'s; then slide it so its right/bottom edges land inside `work`,
+// then clamp its top-left corner to `work`'s origin.
+#[cfg(target_os = "windows")]
+fn fit_rect_to_work_area(desired: RECT, work: RECT) -> RECT {
+    let desired_width = desired.right - desired.left;
+    let desired_height = desired.bottom - desired.top;
+    let work_width = work.right - work.left;
+    let work_height = work.bottom - work.top;
+
+    // Shrink whichever dimension is larger than the work area's.
+    let width = desired_width.min(work_width);
+    let height = desired_height.min(work_height);
+
+    // Slide so the window's right/bottom edges land inside the work area...
+    let mut x = desired.left.min(work.right - width);
+    let mut y = desired.top.min(work.bottom - height);
+
+    // ...then clamp the top-left corner to the work area's origin.
+    x = x.max(work.left);
+    y = y.max(work.top);
+
+    RECT { left: x, top: y, right: x + width, bottom: y + height }
+}
+
+// Clamps `desired` so it lands fully inside the work area of its nearest monitor,
+// then applies it to `hwnd`. Used when restoring a remembered window position that
+// may now be off-screen (e.g. a monitor was unplugged).
+// Returns true if `SetWindowPos` succeeded.
+#[cfg(target_os = "windows")]
+pub fn adjust_to_fit(hwnd: HWND, desired: RECT) -> bool {
+    unsafe {
+        let hmonitor = MonitorFromRect(&desired, MONITOR_DEFAULTTONEAREST);
+        let Some(work) = work_area_of(hmonitor) else {
+            return false;
+        };
+
+        let fitted = fit_rect_to_work_area(desired, work);
+
+        let unchanged = fitted.left == desired.left
+            && fitted.top == desired.top
+            && fitted.right == desired.right
+            && fitted.bottom == desired.bottom;
+
+        let result = if unchanged {
+            let width = desired.right - desired.left;
+            let height = desired.bottom - desired.top;
+            SetWindowPos(hwnd, HWND_TOP, desired.left, desired.top, width, height, SWP_NOZORDER | SWP_NOACTIVATE)
+        } else {
+            let width = fitted.right - fitted.left;
+            let height = fitted.bottom - fitted.top;
+            debug!("Clamping saved window position {:?} to fit work area {:?} -> {:?}", desired, work, fitted);
+            SetWindowPos(hwnd, HWND_TOP, fitted.left, fitted.top, width, height, SWP_NOZORDER | SWP_NOACTIVATE)
+        };
+
+        if let Err(e) = &result {
+            warn!("SetWindowPos failed while fitting window {:?} to monitor: {e}", hwnd);
+        }
+        result.is_ok()
+    }
+}
+
+#[cfg(all(test, target_os = "windows"))]
+mod fit_rect_tests {
+    use super::*;
+
+    fn rect(left: i32, top: i32, right: i32, bottom: i32) -> RECT {
+        RECT { left, top, right, bottom }
+    }
+
+    #[test]
+    fn leaves_a_rect_that_already_fits_untouched() {
+        let work = rect(0, 0, 1920, 1040);
+        let desired = rect(100, 100, 900, 700);
+        assert_eq!(fit_rect_to_work_area(desired, work), desired);
+    }
+
+    #[test]
+    fn slides_a_rect_that_overflows_the_right_and_bottom_edges() {
+        let work = rect(0, 0, 1920, 1040);
+        let desired = rect(1800, 1000, 2200, 1200);
+        let fitted = fit_rect_to_work_area(desired, work);
+
+        assert_eq!(fitted, rect(1520, 840, 1920, 1040));
+    }
+
+    #[test]
+    fn clamps_a_rect_that_overflows_the_top_left_origin() {
+        let work = rect(100, 100, 1920, 1040);
+        let desired = rect(-500, -500, -100, -100);
+        let fitted = fit_rect_to_work_area(desired, work);
+
+        assert_eq!(fitted, rect(100, 100, 500, 500));
+    }
+
+    #[test]
+    fn shrinks_a_rect_larger_than_the_whole_work_area() {
+        let work = rect(0, 0, 1920, 1040);
+        let desired = rect(-200, -200, 3000, 2000);
+        let fitted = fit_rect_to_work_area(desired, work);
+
+        assert_eq!(fitted.right - fitted.left, 1920);
+        assert_eq!(fitted.bottom - fitted.top, 1040);
+        assert!(fitted.left >= work.left && fitted.top >= work.top);
+    }
+
+    #[test]
+    fn handles_a_secondary_monitor_with_a_non_zero_origin() {
+        let work = rect(1920, 0, 3840, 1080);
+        let desired = rect(1700, 50, 2100, 450);
+        let fitted = fit_rect_to_work_area(desired, work);
+
+        assert_eq!(fitted, rect(1920, 50, 2320, 450));
+    }
+}
 
 // Data structure to pass to EnumWindows callback
 #[cfg(target_os = "windows")]
@@ -71,60 +374,311 @@ unsafe extern "system" fn enum_window_proc(hwnd: HWND, lparam: LPARAM) -> BOOL {
     }
 }
 
-// Windows platform window centering function implementation
-// Returns true if successful, false otherwise
+// Finds our own top-level window via the PID-based "largest visible window" heuristic above.
 #[cfg(target_os = "windows")]
-fn center_window_impl() -> bool {
-    let current_pid = std::process::id();
+fn find_own_window() -> Option<HWND> {
     let mut data = EnumWindowData {
-        target_pid: current_pid,
+        target_pid: std::process::id(),
         best_hwnd: None,
         max_area: 0,
     };
 
     unsafe {
-        // Enumerate windows to find ours by PID
         let _ = EnumWindows(Some(enum_window_proc), LPARAM(&mut data as *mut _ as _));
-        
-        if let Some(hwnd) = data.best_hwnd {
-            // Logic to center the found window
-            let mut window_rect = RECT::default();
-            if GetWindowRect(hwnd, &mut window_rect).is_ok() {
-                let window_width = window_rect.right - window_rect.left;
-                let window_height = window_rect.bottom - window_rect.top;
-
-                // Get the monitor where the window is located (or default)
-                let hmonitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTOPRIMARY);
-                
-                // Get monitor information
-                let mut monitor_info = MONITORINFO {
-                    cbSize: std::mem::size_of::<MONITORINFO>() as u32,
-                    ..Default::default()
-                };
-                
-                if GetMonitorInfoW(hmonitor, &mut monitor_info).as_bool() {
-                    let monitor_rect = monitor_info.rcWork; // Use work area (excluding taskbar)
-                    
+    }
+
+    data.best_hwnd
+}
+
+// Gets the real HWND straight from Slint via its `raw-window-handle` integration, when
+// available. This is the preferred way to find our window: it's synchronous and exact,
+// unlike the `find_own_window` PID/size heuristic above, which exists only as a fallback
+// for the brief window during startup where the platform handle isn't created yet.
+#[cfg(target_os = "windows")]
+fn hwnd_from_app(app: &AppWindow) -> Option<HWND> {
+    use slint::ComponentHandle;
+
+    let handle = app.window().window_handle().ok()?;
+    match handle.as_raw() {
+        RawWindowHandle::Win32(win32) => Some(HWND(win32.hwnd.get() as *mut std::ffi::c_void)),
+        _ => None,
+    }
+}
+
+// Name of the mutex used to detect a running instance, and of the broadcast message a
+// second instance sends to ask the first one to come to the foreground.
+#[cfg(target_os = "windows")]
+const SINGLE_INSTANCE_MUTEX_NAME: windows::core::PCWSTR = w!("Local\\WslDashboard::SingleInstanceMutex");
+#[cfg(target_os = "windows")]
+const ACTIVATE_MESSAGE_NAME: windows::core::PCWSTR = w!("WslDashboard::Activate");
+
+// Ensures only one instance of the dashboard runs at a time.
+//
+// Returns true if this is the first (and now sole) instance, and it should proceed to start
+// normally. Returns false if another instance is already running - it has been asked to come
+// to the foreground, and this process should exit immediately.
+#[cfg(target_os = "windows")]
+pub fn ensure_single_instance() -> bool {
+    unsafe {
+        match CreateMutexW(None, true, SINGLE_INSTANCE_MUTEX_NAME) {
+            Ok(handle) => {
+                let already_running = GetLastError() == ERROR_ALREADY_EXISTS;
+                // Held for the lifetime of the process; Windows cleans it up on exit.
+                std::mem::forget(handle);
+
+                if already_running {
+                    debug!("Another instance is already running; activating it instead");
+                    broadcast_activation();
+                    return false;
+                }
+            }
+            Err(e) => {
+                warn!("CreateMutexW failed: {e}; continuing without a single-instance guard");
+                return true;
+            }
+        }
+    }
+
+    spawn_activation_listener();
+    true
+}
+
+// Asks whichever instance owns the single-instance mutex to foreground its window.
+#[cfg(target_os = "windows")]
+fn broadcast_activation() {
+    unsafe {
+        let message = RegisterWindowMessageW(ACTIVATE_MESSAGE_NAME);
+        if message == 0 {
+            warn!("RegisterWindowMessageW failed; cannot activate the running instance");
+            return;
+        }
+        if let Err(e) = PostMessageW(Some(HWND_BROADCAST), message, WPARAM(0), LPARAM(0)) {
+            warn!("Failed to broadcast activation message: {e}");
+        }
+    }
+}
+
+// Window procedure of a hidden top-level window whose sole job is to receive the broadcast activation message and
+// bring our real window to the foreground in response.
+#[cfg(target_os = "windows")]
+unsafe extern "system" fn activation_listener_wndproc(hwnd: HWND, msg: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    // Safety requirement: FFI callback; only ever touches its own arguments.
+    unsafe {
+        if msg == RegisterWindowMessageW(ACTIVATE_MESSAGE_NAME) {
+            if let Some(target) = find_own_window() {
+                let _ = ShowWindow(target, SW_RESTORE);
+                let _ = SetForegroundWindow(target);
+                debug!("Activated existing window {:?} in response to a relaunch", target);
+            } else {
+                warn!("Received activation broadcast but couldn't find our own window");
+            }
+            return LRESULT(0);
+        }
+        DefWindowProcW(hwnd, msg, wparam, lparam)
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn spawn_activation_listener() {
+    std::thread::spawn(|| unsafe {
+        let class_name = w!("WslDashboardActivationListener");
+        let wndclass = WNDCLASSW {
+            lpfnWndProc: Some(activation_listener_wndproc),
+            lpszClassName: class_name,
+            ..Default::default()
+        };
+        // Ignore errors: if the class is already registered (e.g. a prior instance raced us
+        // and already exited), CreateWindowExW below will still work against it.
+        let _ = RegisterClassW(&wndclass);
+
+        // Must be an ordinary top-level window (no parent), not a message-only
+        // (HWND_MESSAGE-parented) one: per Win32, HWND_BROADCAST is delivered only to
+        // top-level unowned windows, and explicitly skips message-only windows. It's kept
+        // unstyled/unshown (WINDOW_STYLE(0) has no WS_VISIBLE) so it never actually appears.
+        let hwnd = CreateWindowExW(
+            WINDOW_EX_STYLE(0),
+            class_name,
+            w!(""),
+            WINDOW_STYLE(0),
+            0, 0, 0, 0,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let Ok(_hwnd) = hwnd else {
+            warn!("Failed to create activation listener window; relaunches won't refocus us");
+            return;
+        };
+
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+            let _ = TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+    });
+}
+
+// Path to the small config file that remembers the dashboard's last window geometry.
+#[cfg(target_os = "windows")]
+fn geometry_config_path() -> Option<std::path::PathBuf> {
+    let mut path = std::path::PathBuf::from(std::env::var_os("APPDATA")?);
+    path.push("WslDashboard");
+    path.push("window_geometry.txt");
+    Some(path)
+}
+
+// Saved as "left,top,right,bottom" - simple enough not to need a parsing crate.
+#[cfg(target_os = "windows")]
+fn save_window_geometry(rect: RECT) {
+    let Some(path) = geometry_config_path() else { return };
+    if let Some(parent) = path.parent() {
+        if let Err(e) = std::fs::create_dir_all(parent) {
+            warn!("Failed to create config dir {:?}: {e}", parent);
+            return;
+        }
+    }
+    let contents = format!("{},{},{},{}", rect.left, rect.top, rect.right, rect.bottom);
+    if let Err(e) = std::fs::write(&path, contents) {
+        warn!("Failed to save window geometry to {:?}: {e}", path);
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn load_window_geometry() -> Option<RECT> {
+    let contents = std::fs::read_to_string(geometry_config_path()?).ok()?;
+    let mut parts = contents.trim().split(',').map(|p| p.parse::<i32>());
+    Some(RECT {
+        left: parts.next()?.ok()?,
+        top: parts.next()?.ok()?,
+        right: parts.next()?.ok()?,
+        bottom: parts.next()?.ok()?,
+    })
+}
+
+// Subclass ID used with `SetWindowSubclass`; arbitrary but must be stable across calls so
+// re-installing the hook (e.g. after a forced re-center) updates rather than stacks.
+#[cfg(target_os = "windows")]
+const GEOMETRY_SAVE_SUBCLASS_ID: usize = 1;
+
+// Subclass proc that saves the window's rect whenever it's moved, resized, or closed, so
+// the persisted geometry reflects the user's actual last position rather than just wherever
+// we last centered it.
+#[cfg(target_os = "windows")]
+unsafe extern "system" fn geometry_save_subclass_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+    _subclass_id: usize,
+    _ref_data: usize,
+) -> LRESULT {
+    // Safety requirement: FFI callback; only ever touches its own arguments.
+    unsafe {
+        if matches!(msg, WM_MOVE | WM_EXITSIZEMOVE | WM_CLOSE | WM_DESTROY) {
+            let mut rect = RECT::default();
+            if GetWindowRect(hwnd, &mut rect).is_ok() {
+                save_window_geometry(rect);
+            }
+        }
+        DefSubclassProc(hwnd, msg, wparam, lparam)
+    }
+}
+
+// Installs the geometry-save subclass on `hwnd`. Safe to call more than once: `SetWindowSubclass`
+// re-installing the same (proc, id) pair just updates it rather than stacking duplicates.
+#[cfg(target_os = "windows")]
+fn install_geometry_save_hook(hwnd: HWND) {
+    unsafe {
+        if SetWindowSubclass(hwnd, Some(geometry_save_subclass_proc), GEOMETRY_SAVE_SUBCLASS_ID, 0).as_bool() {
+            return;
+        }
+    }
+    warn!("Failed to install geometry-save hook on window {:?}; manual moves won't persist", hwnd);
+}
+
+// Finds our window via the PID-based heuristic, then places it. This is the fallback path
+// for when we don't yet have a real HWND from Slint's `window_handle()` (see
+// `hwnd_from_app`) - kept around because there's a brief window during startup where the
+// handle isn't available yet, and it's a reasonable safety net besides.
+// Returns true if successful.
+#[cfg(target_os = "windows")]
+fn place_window_impl(target: MonitorTarget, force_recenter: bool) -> bool {
+    let Some(hwnd) = find_own_window() else {
+        return false;
+    };
+    place_window_at(hwnd, target, force_recenter)
+}
+
+// Centers `hwnd`, or restores its last remembered position if one was saved.
+// Returns true if successful.
+#[cfg(target_os = "windows")]
+fn place_window_at(hwnd: HWND, target: MonitorTarget, force_recenter: bool) -> bool {
+    let current_pid = std::process::id();
+
+    // Keep the persisted geometry in sync with whatever the user does to the window from
+    // here on (move, resize, close), not just the rect we happen to place it at now.
+    install_geometry_save_hook(hwnd);
+
+    unsafe {
+        // Restore the remembered position, unless the caller wants to force re-centering.
+        if !force_recenter {
+            if let Some(saved) = load_window_geometry() {
+                // Run it through the fit-to-monitor clamp in case the monitor layout changed.
+                if adjust_to_fit(hwnd, saved) {
+                    debug!("Restored saved window geometry {:?} (Process: {})", saved, current_pid);
+                    return true;
+                }
+                warn!("Failed to restore saved geometry {:?}; falling back to centering", saved);
+            }
+        }
+
+        // First run (no saved geometry) or a forced re-center: center on the target monitor.
+        let mut window_rect = RECT::default();
+        if GetWindowRect(hwnd, &mut window_rect).is_ok() {
+            let mut window_width = window_rect.right - window_rect.left;
+            let mut window_height = window_rect.bottom - window_rect.top;
+
+            // Resolve the target monitor and, on mixed-DPI setups, scale the window
+            // dimensions to that monitor's DPI before centering - otherwise a window
+            // captured at e.g. 100% scaling ends up off-center once moved to a 150%
+            // panel (or vice versa).
+            if let Some(hmonitor) = resolve_target_monitor(hwnd, target) {
+                let window_dpi = GetDpiForWindow(hwnd);
+                let monitor_dpi = effective_dpi(hmonitor);
+                if window_dpi != 0 && window_dpi != monitor_dpi {
+                    let scale = monitor_dpi as f64 / window_dpi as f64;
+                    window_width = (window_width as f64 * scale).round() as i32;
+                    window_height = (window_height as f64 * scale).round() as i32;
+                }
+
+                if let Some(monitor_rect) = work_area_of(hmonitor) {
                     let monitor_width = monitor_rect.right - monitor_rect.left;
                     let monitor_height = monitor_rect.bottom - monitor_rect.top;
-                    
+
                     // Calculate centered position
                     let x = monitor_rect.left + (monitor_width - window_width) / 2;
                     let y = monitor_rect.top + (monitor_height - window_height) / 2;
-                    
+
                     // Set window position
                     // SWP_FRAMECHANGED causes the frame to be redrawn (useful if non-client area changed)
                     let result = SetWindowPos(
-                        hwnd, 
-                        HWND_TOP, 
-                        x, y, 
-                        0, 0, 
+                        hwnd,
+                        HWND_TOP,
+                        x, y,
+                        0, 0,
                         SWP_NOSIZE | SWP_NOZORDER
                     );
 
                     if result.is_ok() {
-                        debug!("Window centered (Largest Area: {}) at ({}, {}) on monitor {}x{} (Process: {})", 
-                               data.max_area, x, y, monitor_width, monitor_height, current_pid);
+                        debug!("Window centered at ({}, {}) on monitor {}x{} (Process: {})",
+                               x, y, monitor_width, monitor_height, current_pid);
+
+                        let mut final_rect = RECT::default();
+                        if GetWindowRect(hwnd, &mut final_rect).is_ok() {
+                            save_window_geometry(final_rect);
+                        }
                         return true;
                     } else {
                         warn!("SetWindowPos failed for window {:?}", hwnd);
@@ -136,34 +690,170 @@ fn center_window_impl() -> bool {
     false
 }
 
-// Show window and center it
+// Show window, restoring its remembered position (or centering it on the primary monitor
+// on first run).
 pub fn show_and_center(app: &AppWindow) {
+    show_and_center_on(app, MonitorTarget::Primary, false);
+}
+
+// Show window, restoring its remembered position on `target` unless `force_recenter` is set,
+// in which case the saved geometry is ignored and the window is re-centered.
+//
+// Note: per-monitor DPI awareness must already be set by this point (see
+// `ensure_process_dpi_aware`'s doc comment) - it's too late to opt in here.
+#[cfg_attr(not(target_os = "windows"), allow(unused_variables))]
+pub fn show_and_center_on(app: &AppWindow, target: MonitorTarget, force_recenter: bool) {
     #[cfg(target_os = "windows")]
     {
         use slint::ComponentHandle;
         app.show().unwrap();
-        
-        // Execute centering logic in background thread with polling
-        // Using PID-based "Largest Visible Window" lookup for reliability
-        std::thread::spawn(|| {
-            // Try for up to 500ms (50 * 10ms)
-            for i in 0..50 {
-                if center_window_impl() {
-                    // Success!
-                    break;
-                }
-                // Log warning if we retry many times
-                if i == 10 {
-                    debug!("Still looking for main window to center...");
-                }
-                std::thread::sleep(std::time::Duration::from_millis(10));
+
+        // Preferred path: Slint hands us the real HWND synchronously, so we can place the
+        // window immediately after show() with no visible jump from an initial off-center
+        // position.
+        if let Some(hwnd) = hwnd_from_app(app) {
+            if !place_window_at(hwnd, target, force_recenter) {
+                warn!("Failed to place window via Slint's window handle");
             }
-        });
+        } else {
+            // Fallback: the handle isn't available yet (can happen very early in startup).
+            // Poll for our window the old way, via the PID/size heuristic.
+            debug!("Slint window handle not yet available; falling back to PID-based lookup");
+            std::thread::spawn(move || {
+                // Try for up to 500ms (50 * 10ms)
+                for i in 0..50 {
+                    if place_window_impl(target, force_recenter) {
+                        // Success!
+                        break;
+                    }
+                    // Log warning if we retry many times
+                    if i == 10 {
+                        debug!("Still looking for main window to center...");
+                    }
+                    std::thread::sleep(std::time::Duration::from_millis(10));
+                }
+            });
+        }
     }
-    
+
     #[cfg(not(target_os = "windows"))]
     {
         use slint::ComponentHandle;
         app.show().unwrap();
     }
 }
+
+// Settings for the opt-in OLED burn-in guard: periodically nudges the window by a few
+// pixels so a static dashboard doesn't leave a ghost image on a panel that never varies it.
+#[derive(Debug, Clone, Copy)]
+pub struct BurnInGuardConfig {
+    pub enabled: bool,
+    pub interval: std::time::Duration,
+    pub max_offset_px: i32,
+}
+
+impl Default for BurnInGuardConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval: std::time::Duration::from_secs(60),
+            max_offset_px: 4,
+        }
+    }
+}
+
+// Starts the burn-in guard's background ticker if `config.enabled`. No-op otherwise.
+#[cfg_attr(not(target_os = "windows"), allow(unused_variables))]
+pub fn spawn_burn_in_guard(config: BurnInGuardConfig) {
+    if !config.enabled {
+        return;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        debug!("Starting OLED burn-in guard: every {:?}, up to {}px", config.interval, config.max_offset_px);
+        std::thread::spawn(move || loop {
+            std::thread::sleep(config.interval);
+            nudge_window(config.max_offset_px);
+        });
+    }
+}
+
+// A cheap pseudo-random offset in `[-max_offset_px, max_offset_px]`. Burn-in mitigation
+// doesn't need cryptographic randomness, just "not always the same pixel" - avoids pulling
+// in a `rand` dependency for one call site.
+#[cfg(target_os = "windows")]
+fn pseudo_random_offset(max_offset_px: i32, salt: u32) -> i32 {
+    if max_offset_px <= 0 {
+        return 0;
+    }
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let mixed = nanos.wrapping_mul(2_654_435_761).wrapping_add(salt);
+    let range = (max_offset_px as u32) * 2 + 1;
+    (mixed % range) as i32 - max_offset_px
+}
+
+// Nudges our window by a small random offset, bounded to stay inside its monitor's work area.
+#[cfg(target_os = "windows")]
+fn nudge_window(max_offset_px: i32) {
+    let Some(hwnd) = find_own_window() else {
+        return;
+    };
+
+    let mut rect = RECT::default();
+    if unsafe { GetWindowRect(hwnd, &mut rect) }.is_err() {
+        return;
+    }
+
+    let dx = pseudo_random_offset(max_offset_px, 0x9E37_79B1);
+    let dy = pseudo_random_offset(max_offset_px, 0x85EB_CA77);
+
+    let desired = RECT {
+        left: rect.left + dx,
+        top: rect.top + dy,
+        right: rect.right + dx,
+        bottom: rect.bottom + dy,
+    };
+
+    // Reuse the fit-to-monitor clamp so the nudge can never push the window off-screen.
+    adjust_to_fit(hwnd, desired);
+}
+
+#[cfg(all(test, target_os = "windows"))]
+mod pseudo_random_offset_tests {
+    use super::*;
+
+    #[test]
+    fn zero_or_negative_max_offset_always_yields_zero() {
+        for salt in [0, 1, 0x9E37_79B1, 0x85EB_CA77] {
+            assert_eq!(pseudo_random_offset(0, salt), 0);
+            assert_eq!(pseudo_random_offset(-5, salt), 0);
+        }
+    }
+
+    #[test]
+    fn offset_stays_within_bounds() {
+        for max_offset_px in [1, 2, 4, 16, 100] {
+            for salt in 0..32u32 {
+                let offset = pseudo_random_offset(max_offset_px, salt);
+                assert!(
+                    offset >= -max_offset_px && offset <= max_offset_px,
+                    "offset {offset} out of [-{max_offset_px}, {max_offset_px}] for salt {salt}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn different_salts_can_yield_different_offsets() {
+        // Not guaranteed for every possible timestamp, but with a reasonable spread of salts
+        // against the same instant, at least one pair should differ - this is what lets
+        // `nudge_window` move x and y independently instead of moving diagonally every time.
+        let offsets: std::collections::HashSet<i32> =
+            (0..32u32).map(|salt| pseudo_random_offset(16, salt)).collect();
+        assert!(offsets.len() > 1, "expected varied offsets across salts, got {offsets:?}");
+    }
+}